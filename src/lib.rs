@@ -1,7 +1,15 @@
 #![allow(unused_imports)]
 
 use core::convert::{TryFrom, TryInto};
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::fs;
+
 use ogl33::*;
+use ultraviolet::{Mat4, Vec3};
+
+mod texture;
+pub use texture::{Texture2D, TextureRegion};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BufferType {
@@ -12,6 +20,7 @@ pub enum BufferType {
 }
 
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ShaderType {
     // Vertex shaders determine the position of geometry within the screen.
     Vertex = GL_VERTEX_SHADER as isize,
@@ -19,6 +28,9 @@ pub enum ShaderType {
     //
     // Also other values, but mostly color.
     Fragment = GL_FRAGMENT_SHADER as isize,
+    // Geometry shaders run between the vertex and fragment stages and can
+    // amplify geometry, e.g. thickening wireframes.
+    Geometry = GL_GEOMETRY_SHADER as isize,
 }
 
 pub struct Shader(pub GLuint);
@@ -27,7 +39,7 @@ pub struct VertexArray(pub GLuint);
 
 pub struct VertexBuffer(pub GLuint);
 
-pub struct ShaderProgram(pub GLuint, Vec<u32>);
+pub struct ShaderProgram(pub GLuint, HashMap<u32, (String, i32)>);
 
 impl VertexArray {
     // Creates new vertex array object
@@ -56,6 +68,108 @@ impl VertexArray {
     pub fn clear_binding() {
         unsafe { glBindVertexArray(0) }
     }
+
+    // Describes the interleaved vertex layout to GL for this VAO.
+    //
+    // Loops over the entries of `format`, issuing a `glVertexAttribPointer` plus
+    // `glEnableVertexAttribArray` for each so callers no longer copy-paste the
+    // stride/offset arithmetic by hand. `stride` is the number of bytes between
+    // consecutive vertices; pass [`VertexFormat::stride`](VertexFormat::stride)
+    // for the tightly-packed default.
+    pub fn configure_attributes(&self, format: &VertexFormat, stride: i32) {
+        self.bind();
+        for attr in format.attributes() {
+            unsafe {
+                glVertexAttribPointer(
+                    attr.location,
+                    attr.num_components,
+                    attr.gl_type,
+                    if attr.normalized { GL_TRUE } else { GL_FALSE },
+                    stride,
+                    attr.offset as *const _,
+                );
+                glEnableVertexAttribArray(attr.location);
+            }
+        }
+    }
+}
+
+impl Drop for VertexArray {
+    // Frees the VAO when the wrapper goes out of scope so no handle is leaked.
+    fn drop(&mut self) {
+        if self.0 != 0 {
+            unsafe { glDeleteVertexArrays(1, &self.0) };
+        }
+    }
+}
+
+// A single vertex attribute: which shader `location` it feeds, how many
+// components it has, the component `gl_type`, whether integer data is
+// normalized into [0,1]/[-1,1], and its byte `offset` within one vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexAttribute {
+    pub location: u32,
+    pub num_components: i32,
+    pub gl_type: GLenum,
+    pub normalized: bool,
+    pub offset: usize,
+}
+
+// An ordered description of the attributes packed into one vertex.
+//
+// Describe a struct layout once with the builder and the running `offset` and
+// `stride` are accumulated for you, so multiple interleaved attributes can be
+// bound without any hand-written stride/offset arithmetic.
+#[derive(Debug, Clone, Default)]
+pub struct VertexFormat {
+    attributes: Vec<VertexAttribute>,
+    stride: usize,
+}
+
+impl VertexFormat {
+    // Starts an empty format.
+    pub fn new() -> Self {
+        Self {
+            attributes: Vec::new(),
+            stride: 0,
+        }
+    }
+
+    // Appends an attribute at the next free offset and grows the stride by the
+    // size of the added components. Returns `self` so calls can be chained.
+    pub fn attribute(mut self, location: u32, num_components: i32, gl_type: GLenum, normalized: bool) -> Self {
+        self.attributes.push(VertexAttribute {
+            location,
+            num_components,
+            gl_type,
+            normalized,
+            offset: self.stride,
+        });
+        self.stride += num_components.max(0) as usize * gl_type_size(gl_type);
+        self
+    }
+
+    // The attributes in declaration order.
+    pub fn attributes(&self) -> &[VertexAttribute] {
+        &self.attributes
+    }
+
+    // The tightly-packed stride, i.e. the summed size of every component.
+    pub fn stride(&self) -> i32 {
+        self.stride.try_into().unwrap()
+    }
+}
+
+// Size in bytes of one component of the given GL data type.
+fn gl_type_size(gl_type: GLenum) -> usize {
+    match gl_type {
+        GL_BYTE | GL_UNSIGNED_BYTE => 1,
+        GL_SHORT | GL_UNSIGNED_SHORT => 2,
+        GL_INT | GL_UNSIGNED_INT | GL_FLOAT => 4,
+        GL_DOUBLE => 8,
+        // Fall back to a 4-byte word for any type we don't size explicitly.
+        _ => 4,
+    }
 }
 
 impl VertexBuffer {
@@ -88,6 +202,15 @@ impl VertexBuffer {
     }
 }
 
+impl Drop for VertexBuffer {
+    // Frees the buffer object when the wrapper goes out of scope.
+    fn drop(&mut self) {
+        if self.0 != 0 {
+            unsafe { glDeleteBuffers(1, &self.0) };
+        }
+    }
+}
+
 impl Shader {
     // Makes a new shader.
     //
@@ -146,31 +269,33 @@ impl Shader {
         String::from_utf8_lossy(&v).into_owned()
     }
 
-    // Marks a shader for deletion.
-    //
-    // Note: This _does not_ immediately delete the shader. It only marks it for
-    // deletion. If the shader has been previously attached to a program then the
-    // shader will stay allocated until it's unattached from that program.
-    pub fn delete(self) {
-        unsafe { glDeleteShader(self.0) };
-    }
-
     /// Takes a shader type and source string and produces either the compiled
     /// shader or an error message.
     ///
     /// Prefer [`ShaderProgram::from_vert_frag`](ShaderProgram::from_vert_frag),
     /// it makes a complete program from the vertex and fragment sources all at
     /// once.
-    pub fn from_source(ty: ShaderType, source: &str) -> Result<Self, String> {
-        let id = Self::new(ty).ok_or_else(|| "Could not allocate new shader".to_string())?;
+    pub fn from_source(ty: ShaderType, source: &str) -> Result<Self, Error> {
+        let id = Self::new(ty).ok_or(Error::AllocFailed("shader"))?;
         id.set_source(source);
         id.compile();
         if id.compile_success() {
             Ok(id)
         } else {
-            let out = id.info_log();
-            id.delete();
-            Err(out)
+            // `id` is freed by its Drop impl as it leaves scope here.
+            Err(Error::CompileError(id.info_log()))
+        }
+    }
+}
+
+impl Drop for Shader {
+    // Marks the shader for deletion when the wrapper goes out of scope.
+    //
+    // Note: if the shader is still attached to a program it stays allocated
+    // until it's unattached from that program.
+    fn drop(&mut self) {
+        if self.0 != 0 {
+            unsafe { glDeleteShader(self.0) };
         }
     }
 }
@@ -184,7 +309,7 @@ impl ShaderProgram {
     pub fn new() -> Option<Self> {
         let prog = unsafe { glCreateProgram() };
         if prog != 0 {
-            Some(Self(prog,vec![]))
+            Some(Self(prog, HashMap::new()))
         } else {
             None
         }
@@ -200,15 +325,6 @@ impl ShaderProgram {
         unsafe { glLinkProgram(self.0) };
     }
 
-    pub fn get_shader(&self, shader: &str) -> Option<u32>{
-        let index = self.1.iter().position(|&x| x == shader.hash()).unwrap_or_else(|| usize::MAX);
-        if index != usize::MAX {
-            Some(self.1[(index + 1) as usize])
-        }else{
-            None
-        }
-    }
-
     pub fn link_success(&self) -> bool {
         let mut success = 0;
         unsafe { glGetProgramiv(self.0, GL_LINK_STATUS, &mut success) };
@@ -220,11 +336,11 @@ impl ShaderProgram {
     // This is usually used to check the message when a program failed to link.
     pub fn info_log(&self) -> String {
         let mut needed_len = 0;
-        unsafe { glGetShaderiv(self.0, GL_INFO_LOG_LENGTH, &mut needed_len) };
+        unsafe { glGetProgramiv(self.0, GL_INFO_LOG_LENGTH, &mut needed_len) };
         let mut v: Vec<u8> = Vec::with_capacity(needed_len.try_into().unwrap());
         let mut len_written = 0_i32;
         unsafe {
-            glGetShaderInfoLog(
+            glGetProgramInfoLog(
                 self.0,
                 v.capacity().try_into().unwrap(),
                 &mut len_written,
@@ -235,84 +351,345 @@ impl ShaderProgram {
         String::from_utf8_lossy(&v).into_owned()
     }
 
-    // Marks the program for deletion.
-    //
-    // Note: This _does not_ immediately delete the program. If the program is
-    // currently in use it won't be deleted until it's not the active program.
-    // When a program is finally deleted and attached shaders are unattached.
-    pub fn delete(self) {
-        unsafe { glDeleteProgram(self.0) };
-    }
-
     pub fn use_program(&self) {
         unsafe { glUseProgram(self.0) };
     }
 
+    // Looks up the location of a uniform, caching the name->location mapping so
+    // repeated per-frame sets don't re-query the driver. The name hash (the same
+    // `str::hash` used elsewhere in this crate) keys the cache, but the name is
+    // stored alongside the location and compared on a hit so two names that
+    // hash-collide can never misroute a write. Returns an error when the uniform
+    // is not present in the linked program.
+    pub fn uniform_location(&mut self, name: &str) -> Result<i32, Error> {
+        let key = name.hash();
+        if let Some((cached_name, loc)) = self.1.get(&key) {
+            if cached_name == name {
+                return Ok(*loc);
+            }
+            // Hash collision with a different name: fall through and query the
+            // driver rather than returning the other uniform's location.
+        }
+        let c_name = CString::new(name).map_err(|_| Error::BadCString)?;
+        let loc = unsafe { glGetUniformLocation(self.0, c_name.as_ptr().cast()) };
+        if loc < 0 {
+            return Err(Error::UniformNotFound(name.to_string()));
+        }
+        self.1.insert(key, (name.to_string(), loc));
+        Ok(loc)
+    }
+
+    // Sets a scalar `float` uniform. The program must be the active one.
+    pub fn set_uniform_f32(&mut self, name: &str, value: f32) -> Result<(), Error> {
+        let loc = self.uniform_location(name)?;
+        unsafe { glUniform1f(loc, value) };
+        Ok(())
+    }
+
+    // Sets a `vec3` uniform from an `ultraviolet::Vec3`.
+    pub fn set_uniform_vec3(&mut self, name: &str, value: Vec3) -> Result<(), Error> {
+        let loc = self.uniform_location(name)?;
+        unsafe { glUniform3f(loc, value.x, value.y, value.z) };
+        Ok(())
+    }
+
+    // Sets a `mat4` uniform from an `ultraviolet::Mat4`. The matrix is laid out
+    // column-major and `repr(C)`, so it can be handed to GL without transposing.
+    pub fn set_uniform_mat4(&mut self, name: &str, value: &Mat4) -> Result<(), Error> {
+        let loc = self.uniform_location(name)?;
+        unsafe {
+            glUniformMatrix4fv(loc, 1, GL_FALSE, (value as *const Mat4).cast());
+        }
+        Ok(())
+    }
+
 
     // Takes a vertex shader source string and a fragment shader source string
     // and either gets you a working program object or gets you an error message.
     //
     // This is the preferred way to create a simple shader program in the common
     // case. It's just less error prone than doing all the steps yourself.
-    pub fn from_vert_frag(vert: &str, frag: &str) -> Result<Self, String> {
-        let mut p = Self::new().ok_or_else(|| "Could not allocate a program".to_string())?;
-        let v = Shader::from_source(ShaderType::Vertex, vert)
-            .map_err(|e| format!("Vertex Compile Error: {}", e))?;
-        let f = Shader::from_source(ShaderType::Fragment, frag)
-            .map_err(|e| format!("Fragment Compile Error: {}", e))?;
+    pub fn from_vert_frag(vert: &str, frag: &str) -> Result<Self, Error> {
+        let mut p = Self::new().ok_or(Error::AllocFailed("program"))?;
+        let v = Shader::from_source(ShaderType::Vertex, vert)?;
+        let f = Shader::from_source(ShaderType::Fragment, frag)?;
 
         p.attach_shader(&v);
-        //TODO:
-        // Gör hash funktionen mer effektiv
-        // Just nu hashar den hela shadern (alltså koden)
-        // Kan lösas genom att endast namnge shadern i en kommentar
-        // Och göra en smart hashShader funktion
-        p.1.push(vert.hash());
-        p.1.push(v.0);
         p.attach_shader(&f);
-        p.1.push(frag.hash());
-        p.1.push(f.0);
 
         p.link_program();
-        v.delete();
-        f.delete();
+        // `v` and `f` are freed by their Drop impls at the end of this scope;
+        // the driver keeps them alive until they're detached from the program.
+        if p.link_success() {
+            Ok(p)
+        } else {
+            // `p` is freed by its Drop impl as it leaves scope here.
+            Err(Error::LinkError(p.info_log()))
+        }
+    }
+
+    // Compiles and attaches an arbitrary set of stages, then links them.
+    //
+    // Unlike [`from_vert_frag`](ShaderProgram::from_vert_frag) this takes any
+    // mix of stages, so a geometry stage can be slotted between the vertex and
+    // fragment stages. The stage set is validated first: every pipeline needs a
+    // vertex shader. (Tessellation and compute stages are GL 4.x features and
+    // aren't available through the 3.3-core `ogl33` binding this crate uses.)
+    pub fn from_stages(stages: &[(ShaderType, &str)]) -> Result<Self, Error> {
+        let has_vertex = stages.iter().any(|(ty, _)| matches!(ty, ShaderType::Vertex));
+        if !has_vertex {
+            return Err(Error::MissingStage("a vertex shader"));
+        }
+
+        let mut p = Self::new().ok_or(Error::AllocFailed("program"))?;
+        // Keep the shaders alive until after linking; their Drop impls free them
+        // when this scope ends.
+        let mut shaders: Vec<Shader> = Vec::with_capacity(stages.len());
+        for (ty, src) in stages {
+            let shader = Shader::from_source(*ty, src)?;
+            p.attach_shader(&shader);
+            shaders.push(shader);
+        }
+
+        p.link_program();
         if p.link_success() {
             Ok(p)
         } else {
-            let out = format!("Program Link Error: {}", p.info_log());
-            p.delete();
-            Err(out)
+            Err(Error::LinkError(p.info_log()))
+        }
+    }
+}
+
+impl Drop for ShaderProgram {
+    // Frees the program object when the wrapper goes out of scope. Attached
+    // shaders are unattached as the program is finally deleted.
+    fn drop(&mut self) {
+        if self.0 != 0 {
+            unsafe { glDeleteProgram(self.0) };
         }
     }
 }
 
+// A cache of linked programs keyed by the hash of their source.
+//
+// Built for live editing: call [`reload_if_changed`](ShaderCache::reload_if_changed)
+// once per frame with the paths of the shader files on disk. When the combined
+// source hash is unchanged nothing happens; when it changes the program is
+// relinked (or reused from the cache if that exact source was seen before) and
+// made the active program. A compile error keeps the previously active program
+// so an in-progress edit doesn't crash the running session.
+#[derive(Default)]
+pub struct ShaderCache {
+    programs: HashMap<u32, ShaderProgram>,
+    active: Option<u32>,
+}
+
+impl ShaderCache {
+    // Starts an empty cache with no active program.
+    pub fn new() -> Self {
+        Self {
+            programs: HashMap::new(),
+            active: None,
+        }
+    }
+
+    // Combines the two source hashes into the cache key for a vert+frag pair.
+    //
+    // The full body is hashed so any edit to a shader's source changes the key
+    // and triggers a relink.
+    fn key(vert: &str, frag: &str) -> u32 {
+        vert.hash().wrapping_mul(31).wrapping_add(frag.hash())
+    }
+
+    // The currently active program, if one has been linked.
+    pub fn active(&self) -> Option<&ShaderProgram> {
+        self.active.and_then(|key| self.programs.get(&key))
+    }
+
+    // Reads the shader files, and if their source hash differs from the active
+    // program, relinks (or reuses a cached program) and makes it active.
+    //
+    // Returns the now-active program. On a compile/link error the previously
+    // active program is returned unchanged so a live editing session keeps
+    // running; the error only propagates when there is no program to fall back
+    // to yet.
+    pub fn reload_if_changed(
+        &mut self,
+        vert_path: &str,
+        frag_path: &str,
+    ) -> Result<&ShaderProgram, Error> {
+        let vert = fs::read_to_string(vert_path).map_err(|e| Error::FileRead(e.to_string()))?;
+        let frag = fs::read_to_string(frag_path).map_err(|e| Error::FileRead(e.to_string()))?;
+        let key = Self::key(&vert, &frag);
+
+        // Nothing on disk changed since the active program was linked.
+        if self.active == Some(key) {
+            return Ok(self.programs.get(&key).unwrap());
+        }
+
+        if !self.programs.contains_key(&key) {
+            match ShaderProgram::from_vert_frag(&vert, &frag) {
+                Ok(prog) => {
+                    self.programs.insert(key, prog);
+                }
+                Err(e) => {
+                    // Keep running on the last good program if we have one.
+                    if let Some(old) = self.active {
+                        return Ok(self.programs.get(&old).unwrap());
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        self.active = Some(key);
+        let prog = self.programs.get(&key).unwrap();
+        prog.use_program();
+        Ok(prog)
+    }
+}
+
 // Sends data
-pub fn buffer_data(ty: BufferType, data: &[u8], usage: GLenum) {
-    unsafe {
-        glBufferData(
-            // Specifies the binding target we want to buffer to
-            ty as GLenum,
-            // The number of bytes we want to buffer(send)
-            data.len().try_into().unwrap(),
-            // The pointer to the start of the data
-            data.as_ptr().cast(),
-            // The usage hint
-            // Some tasks are easier for the GPU other for the CPU
-            // If we hint how the data will be used then GL will be able to make a smart choice
-            // In this case we want GL_STATIC_DRAW "since we'll just be sending the data once, and then GL will draw with it many times."
-            usage,
-        );
+pub fn buffer_data(ty: BufferType, data: &[u8], usage: GLenum) -> Result<(), Error> {
+    // The byte count has to fit into the signed size GL expects; surface an
+    // error instead of aborting when it doesn't.
+    let len = data.len().try_into().map_err(|_| Error::SizeOverflow)?;
+    // Wrapped in `gl_call!` so the GL error queue is drained after the upload in
+    // debug builds, same as the draw path.
+    crate::gl_call!(glBufferData(
+        // Specifies the binding target we want to buffer to
+        ty as GLenum,
+        // The number of bytes we want to buffer(send)
+        len,
+        // The pointer to the start of the data
+        data.as_ptr().cast(),
+        // The usage hint lets GL pick a smart allocation strategy.
+        usage,
+    ));
+    Ok(())
+}
+
+pub fn buffer_sub_data(ty: BufferType, data: &[u8], offset: usize) -> Result<(), Error> {
+    let offset = offset.try_into().map_err(|_| Error::SizeOverflow)?;
+    let len = data.len().try_into().map_err(|_| Error::SizeOverflow)?;
+    crate::gl_call!(glBufferSubData(ty as GLenum, offset, len, data.as_ptr().cast()));
+    Ok(())
+}
+
+// A single error code reported by `glGetError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GlError {
+    InvalidEnum,
+    InvalidValue,
+    InvalidOperation,
+    InvalidFramebufferOperation,
+    OutOfMemory,
+    // Any code GL hands back that we don't map explicitly.
+    Unknown(GLenum),
+}
+
+impl GlError {
+    // Maps a raw `GL_*` error code to its variant.
+    fn from_code(code: GLenum) -> Self {
+        match code {
+            GL_INVALID_ENUM => GlError::InvalidEnum,
+            GL_INVALID_VALUE => GlError::InvalidValue,
+            GL_INVALID_OPERATION => GlError::InvalidOperation,
+            GL_INVALID_FRAMEBUFFER_OPERATION => GlError::InvalidFramebufferOperation,
+            GL_OUT_OF_MEMORY => GlError::OutOfMemory,
+            other => GlError::Unknown(other),
+        }
     }
 }
 
-pub fn buffer_sub_data(ty:BufferType, data: &[u8], offset: usize){
-    unsafe{
-        glBufferSubData(
-            ty as GLenum,
-            offset.try_into().unwrap(),
-            data.len().try_into().unwrap(),
-            data.as_ptr().cast(),
-        );
+// Drains the GL error queue.
+//
+// `glGetError` only returns one code per call and GL can queue several, so it
+// must be polled in a loop until it reports `GL_NO_ERROR`. Returns every code
+// that was pending, or `Ok(())` when the queue was already empty.
+pub fn check_gl_errors() -> Result<(), Vec<GlError>> {
+    let mut errors = Vec::new();
+    loop {
+        let code = unsafe { glGetError() };
+        if code == GL_NO_ERROR {
+            break;
+        }
+        errors.push(GlError::from_code(code));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+// Runs a GL call and, in debug builds, drains the error queue afterwards.
+//
+// The wrapped expression is evaluated inside an `unsafe` block and its value is
+// returned. When `debug_assertions` are on, any queued errors are reported to
+// stderr tagged with the originating call, so mistakes surface right where they
+// happen instead of at some unrelated later call.
+#[macro_export]
+macro_rules! gl_call {
+    ($call:expr) => {{
+        let ret = unsafe { $call };
+        #[cfg(debug_assertions)]
+        {
+            if let Err(errors) = $crate::check_gl_errors() {
+                eprintln!("GL error(s) after `{}`: {:?}", stringify!($call), errors);
+            }
+        }
+        ret
+    }};
+}
+
+// The crate-wide error type for recoverable failures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    // A shader failed to compile; holds the driver's info log.
+    CompileError(String),
+    // A program failed to link; holds the driver's info log.
+    LinkError(String),
+    // A string handed to GL contained an interior nul byte.
+    BadCString,
+    // A uniform name was not found in the linked program.
+    UniformNotFound(String),
+    // A shader file on disk could not be read; holds the OS error message.
+    FileRead(String),
+    // A required shader stage was missing from a `from_stages` call; names what
+    // the program needed.
+    MissingStage(&'static str),
+    // A GL object could not be allocated; names the kind that failed.
+    AllocFailed(&'static str),
+    // A size or offset did not fit into the signed integer GL expects.
+    SizeOverflow,
+    // One or more errors were reported by `glGetError`.
+    Gl(Vec<GlError>),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::CompileError(log) => write!(f, "shader compile error: {}", log),
+            Error::LinkError(log) => write!(f, "program link error: {}", log),
+            Error::BadCString => write!(f, "string contained an interior nul byte"),
+            Error::UniformNotFound(name) => {
+                write!(f, "uniform \"{}\" not found in linked program", name)
+            }
+            Error::FileRead(msg) => write!(f, "could not read shader file: {}", msg),
+            Error::MissingStage(need) => write!(f, "shader program requires {}", need),
+            Error::AllocFailed(kind) => write!(f, "could not allocate a new {}", kind),
+            Error::SizeOverflow => write!(f, "size or offset did not fit into a GLsizeiptr/GLintptr"),
+            Error::Gl(errors) => write!(f, "GL error(s): {:?}", errors),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<Vec<GlError>> for Error {
+    fn from(errors: Vec<GlError>) -> Self {
+        Error::Gl(errors)
     }
 }
 
@@ -322,7 +699,6 @@ pub fn clear_color(r: f32, g: f32, b: f32, a: f32) {
 
 pub trait Hashable {
     fn hash(&self) -> u32;
-    fn hashShader(&self) -> u32;
 }
 
 impl Hashable for str {
@@ -343,21 +719,6 @@ impl Hashable for str {
         hash
     }
 
-    fn hashShader(&self) -> u32{
-
-        let mut hash: u32 = 0;
-        for _c in self.encode_utf16() {
-            if _c == 35{
-                break;
-            }
-            hash = u32::from(_c)
-                .wrapping_add(hash << 6)
-                .wrapping_add(hash << 16)
-                .wrapping_sub(hash);
-        }
-        hash
-    }
-
 }
 
 
@@ -378,3 +739,46 @@ pub fn polygon_mode(mode: PolygonMode){
     };
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glerror_maps_known_codes() {
+        assert_eq!(GlError::from_code(GL_INVALID_ENUM), GlError::InvalidEnum);
+        assert_eq!(GlError::from_code(GL_OUT_OF_MEMORY), GlError::OutOfMemory);
+        assert_eq!(GlError::from_code(0xBEEF), GlError::Unknown(0xBEEF));
+    }
+
+    #[test]
+    fn vertex_format_accumulates_offsets_and_stride() {
+        // Interleaved vec3 position + vec2 uv + 4 normalized bytes of color.
+        let format = VertexFormat::new()
+            .attribute(0, 3, GL_FLOAT, false)
+            .attribute(1, 2, GL_FLOAT, false)
+            .attribute(2, 4, GL_UNSIGNED_BYTE, true);
+        let attrs = format.attributes();
+        assert_eq!(attrs.len(), 3);
+        assert_eq!(attrs[0].offset, 0);
+        assert_eq!(attrs[1].offset, 12); // after 3 * f32
+        assert_eq!(attrs[2].offset, 20); // after 3 * f32 + 2 * f32
+        assert!(attrs[2].normalized);
+        assert_eq!(format.stride(), 24); // 12 + 8 + 4
+    }
+
+    #[test]
+    fn error_display_is_descriptive() {
+        assert!(Error::CompileError("oops".into())
+            .to_string()
+            .contains("oops"));
+        assert!(Error::UniformNotFound("u_time".into())
+            .to_string()
+            .contains("u_time"));
+        assert_eq!(
+            Error::MissingStage("a vertex shader").to_string(),
+            "shader program requires a vertex shader"
+        );
+        assert!(Error::SizeOverflow.to_string().contains("GLsizei"));
+    }
+}
+
@@ -125,14 +125,16 @@ fn main() {
         //DRAW
         unsafe {
             glClear(GL_COLOR_BUFFER_BIT);
-            //glDrawArrays(GL_TRIANGLES, 0, vert_vec.len().try_into().unwrap());
-            glDrawElements(
-                GL_TRIANGLES,
-                (indicies_vec.len()*3).try_into().unwrap(),
-                GL_UNSIGNED_INT,
-                0 as *const _,
-            );
         }
+        //glDrawArrays(GL_TRIANGLES, 0, vert_vec.len().try_into().unwrap());
+        // Draw through `gl_call!` so any queued GL errors are reported in debug
+        // builds right after the draw that produced them.
+        gl_call!(glDrawElements(
+            GL_TRIANGLES,
+            (indicies_vec.len() * 3).try_into().unwrap(),
+            GL_UNSIGNED_INT,
+            0 as *const _,
+        ));
         win.swap_window(); // Swap the draw_buffer and the display buffer which actually displays what we have drawn.
     }
 }
@@ -147,7 +149,8 @@ pub fn update_vbo(
     let offset = vert_offset * size_of::<Vertex>();
     vao.bind();
     vbo.bind(BufferType::Array);
-    rustCad::buffer_sub_data(BufferType::Array, bytemuck::cast_slice(vertices), offset);
+    rustCad::buffer_sub_data(BufferType::Array, bytemuck::cast_slice(vertices), offset)
+        .expect("could not upload vertex data");
     return vbo;
 }
 
@@ -161,7 +164,8 @@ pub fn update_single_vbo(
     let offset = vertex_num * size_of::<Vertex>();
     vao.bind();
     vbo.bind(BufferType::Array);
-    rustCad::buffer_sub_data(BufferType::Array, bytemuck::cast_slice(vertices), offset);
+    rustCad::buffer_sub_data(BufferType::Array, bytemuck::cast_slice(vertices), offset)
+        .expect("could not upload vertex data");
     return vbo;
 }
 
@@ -173,7 +177,8 @@ pub fn update_whole_vbo(
     let vertices: &[Vertex] = &verts[..];
     vao.bind();
     vbo.bind(BufferType::Array);
-    rustCad::buffer_sub_data(BufferType::Array, bytemuck::cast_slice(vertices), 0);
+    rustCad::buffer_sub_data(BufferType::Array, bytemuck::cast_slice(vertices), 0)
+        .expect("could not upload vertex data");
     return vbo;
 }
 
@@ -187,22 +192,15 @@ pub fn create_vbo(vao: &rustCad::VertexArray, verts: &Vec<Vertex>) -> rustCad::V
         BufferType::Array,
         bytemuck::cast_slice(vertices),
         GL_DYNAMIC_DRAW,
-    );
+    )
+    .expect("could not upload vertex data");
 
-    unsafe {
-        // How will the GPU know the correct way to use/interpret the data we sent it? We describe the "vertex attributes" and then it will be able to interpret these correctly
-        // For each vertex attribute we have to call "glVertexAttribPointer"
-        glVertexAttribPointer(
-            0,        // The index of the attribute we want to describe
-            3, // The number of components in the attribute (in this case 3 since each posistion consists of 3D XYZ posistion)
-            GL_FLOAT, // The type of data in/for the attribute
-            GL_FALSE, // Has to do fixed_point data values, dunno cheif
-            //Alternately, we can use size_of::<f32>() * 3
-            size_of::<Vertex>().try_into().unwrap(), // "The number of bytes from the start of this attribute in one vertex to the start of the same attribute in the next vertex"
-            0 as *const _, // (pointer to) The starting point of the vertex attribute in the buffer
-        );
-        glEnableVertexAttribArray(0);
-    }
+    // Describe the vertex layout once and let the VAO issue the
+    // glVertexAttribPointer/glEnableVertexAttribArray calls for us. A single
+    // vec3 position bound at location 0; more attributes (normals, UVs, colors)
+    // are just further `.attribute(..)` calls.
+    let format = VertexFormat::new().attribute(0, 3, GL_FLOAT, false);
+    vao.configure_attributes(&format, format.stride());
 
     return vbo;
 }
@@ -218,6 +216,7 @@ pub fn create_ebo(vao: &rustCad::VertexArray, inds: &Vec<TriIndexes>) -> rustCad
         BufferType::ElementArray,
         bytemuck::cast_slice(indicies),
         GL_DYNAMIC_DRAW,
-    );
+    )
+    .expect("could not upload index data");
     return ebo;
 }
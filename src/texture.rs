@@ -0,0 +1,132 @@
+use core::convert::TryInto;
+
+use ogl33::*;
+
+// A rectangular region of a texture, used when streaming a partial update into
+// an already-allocated `Texture2D`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+// An owned 2D texture object.
+//
+// The handle is freed automatically by the [`Drop`] impl, the same way the
+// buffer and program wrappers in this crate manage their GL objects.
+pub struct Texture2D {
+    pub handle: GLuint,
+    width: i32,
+    height: i32,
+}
+
+impl Texture2D {
+    // Allocates a new texture and uploads `data` as its level-0 image.
+    //
+    // `internal_format` is how GL stores the texels (e.g. `GL_RGBA8`), while
+    // `format`/`ty` describe the layout of `data` (e.g. `GL_RGBA` /
+    // `GL_UNSIGNED_BYTE`). `filter` is applied to both the min and mag filters;
+    // the wrap modes are set to `GL_REPEAT` on both axes.
+    pub fn with_data(
+        data: &[u8],
+        width: i32,
+        height: i32,
+        internal_format: GLint,
+        format: GLenum,
+        ty: GLenum,
+        filter: GLenum,
+    ) -> Option<Self> {
+        let mut handle = 0;
+        unsafe { glGenTextures(1, &mut handle) };
+        // As with the other generators in this crate, a 0 name means failure.
+        if handle == 0 {
+            return None;
+        }
+        unsafe {
+            glBindTexture(GL_TEXTURE_2D, handle);
+            glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_S, GL_REPEAT as GLint);
+            glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_T, GL_REPEAT as GLint);
+            glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MIN_FILTER, filter as GLint);
+            glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MAG_FILTER, filter as GLint);
+            glTexImage2D(
+                GL_TEXTURE_2D,
+                0,
+                internal_format,
+                width,
+                height,
+                0,
+                format,
+                ty,
+                data.as_ptr().cast(),
+            );
+        }
+        Some(Self {
+            handle,
+            width,
+            height,
+        })
+    }
+
+    // Binds this texture to the 2D target of the currently active texture unit.
+    pub fn bind(&self) {
+        unsafe { glBindTexture(GL_TEXTURE_2D, self.handle) };
+    }
+
+    pub fn clear_binding() {
+        unsafe { glBindTexture(GL_TEXTURE_2D, 0) };
+    }
+
+    // Activates texture unit `unit` (`GL_TEXTURE0 + unit`) and binds this
+    // texture to it, ready to be paired with a `sampler2D` uniform.
+    pub fn bind_to_unit(&self, unit: u32) {
+        unsafe {
+            glActiveTexture(GL_TEXTURE0 + unit);
+            glBindTexture(GL_TEXTURE_2D, self.handle);
+        }
+    }
+
+    // Replaces the texels inside `region` with `data`.
+    //
+    // `data` is read as a tightly packed `region.width × region.height` block:
+    // `GL_UNPACK_ROW_LENGTH` is set to 0 (each row immediately follows the
+    // previous one) for the upload, then restored to 0 so later uploads are
+    // unaffected. A caller that wants to lift a sub-rectangle out of a
+    // wider source buffer should slice the rows to `region.width` first.
+    pub fn update(&self, region: TextureRegion, data: &[u8], format: GLenum, ty: GLenum) {
+        unsafe {
+            glBindTexture(GL_TEXTURE_2D, self.handle);
+            glPixelStorei(GL_UNPACK_ROW_LENGTH, 0);
+            glTexSubImage2D(
+                GL_TEXTURE_2D,
+                0,
+                region.x,
+                region.y,
+                region.width,
+                region.height,
+                format,
+                ty,
+                data.as_ptr().cast(),
+            );
+            glPixelStorei(GL_UNPACK_ROW_LENGTH, 0);
+        }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+}
+
+impl Drop for Texture2D {
+    // Frees the texture object when the wrapper goes out of scope.
+    fn drop(&mut self) {
+        if self.handle != 0 {
+            unsafe { glDeleteTextures(1, &self.handle) };
+        }
+    }
+}